@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri::Manager;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AppSettings {
+    pub visible_on_all_workspaces: bool,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            visible_on_all_workspaces: true,
+        }
+    }
+}
+
+fn settings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    // Deliberately distinct from the "settings.json" file `tauri_plugin_store`
+    // manages for `llm`/`semantic_index` (API keys, embedding config): that
+    // plugin caches and rewrites its whole file on save, so sharing a
+    // filename with this module's raw `fs::write` would let whichever one
+    // persists last silently clobber the other's data.
+    path.push("app_settings.json");
+    Ok(path)
+}
+
+pub fn load(app: &AppHandle) -> AppSettings {
+    let Ok(path) = settings_path(app) else {
+        return AppSettings::default();
+    };
+
+    if !path.exists() {
+        return AppSettings::default();
+    }
+
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(app: &AppHandle, settings: &AppSettings) -> Result<(), String> {
+    let path = settings_path(app)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let json = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Applies the persisted `visible_on_all_workspaces` choice to the main window.
+pub fn apply_to_window(app: &AppHandle, settings: &AppSettings) -> Result<(), String> {
+    let window = app.get_webview_window("main").ok_or("main window not found")?;
+    window
+        .set_visible_on_all_workspaces(settings.visible_on_all_workspaces)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn load_app_settings(app: AppHandle) -> Result<AppSettings, String> {
+    Ok(load(&app))
+}
+
+#[tauri::command]
+pub async fn set_visible_on_all_workspaces(app: AppHandle, enabled: bool) -> Result<AppSettings, String> {
+    let mut settings = load(&app);
+    settings.visible_on_all_workspaces = enabled;
+    apply_to_window(&app, &settings)?;
+    save(&app, &settings)?;
+    Ok(settings)
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+pub const EVENT_SELECTION_CAPTURED: &str = "selection-captured";
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    /// Simulates Cmd+C via Core Graphics so whatever is selected under the
+    /// cursor lands on the clipboard.
+    pub fn trigger_copy() {
+        let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState).unwrap();
+        let c_keycode = 8u16; // Key code for 'c'
+
+        if let Ok(key_down_event) = CGEvent::new_keyboard_event(source.clone(), c_keycode, true) {
+            key_down_event.set_flags(CGEventFlags::CGEventFlagCommand);
+            key_down_event.post(CGEventTapLocation::HID);
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+
+        if let Ok(key_up_event) = CGEvent::new_keyboard_event(source, c_keycode, false) {
+            key_up_event.set_flags(CGEventFlags::CGEventFlagCommand);
+            key_up_event.post(CGEventTapLocation::HID);
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, VIRTUAL_KEY,
+        VK_C, VK_CONTROL,
+    };
+
+    /// Synthesizes Ctrl+C via `SendInput` so whatever is selected under the
+    /// cursor lands on the clipboard.
+    pub fn trigger_copy() {
+        let inputs = [
+            key_event(VK_CONTROL, false),
+            key_event(VK_C, false),
+            key_event(VK_C, true),
+            key_event(VK_CONTROL, true),
+        ];
+
+        unsafe {
+            SendInput(&inputs, std::mem::size_of::<INPUT>() as i32);
+        }
+    }
+
+    fn key_event(key: VIRTUAL_KEY, key_up: bool) -> INPUT {
+        INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: key,
+                    wScan: 0,
+                    dwFlags: if key_up { KEYEVENTF_KEYUP } else { Default::default() },
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use x11_clipboard::Clipboard;
+
+    /// Reads the `PRIMARY` selection (the text highlighted under the
+    /// cursor), falling back to `CLIPBOARD` when nothing is selected. Most
+    /// Wayland compositors forward this through XWayland, so this path
+    /// covers both.
+    pub fn read_selection() -> Option<String> {
+        let clipboard = Clipboard::new().ok()?;
+
+        read_atom(&clipboard, clipboard.getter.atoms.primary)
+            .or_else(|| read_atom(&clipboard, clipboard.getter.atoms.clipboard))
+    }
+
+    fn read_atom(clipboard: &Clipboard, selection: x11_clipboard::xcb::Atom) -> Option<String> {
+        clipboard
+            .load_wait(
+                selection,
+                clipboard.getter.atoms.utf8_string,
+                clipboard.getter.atoms.property,
+            )
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .filter(|text| !text.trim().is_empty())
+    }
+}
+
+/// Captures whatever text is currently selected in the foreground
+/// application and emits it to the frontend as [`EVENT_SELECTION_CAPTURED`].
+pub fn capture_and_emit(app: &AppHandle) {
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(text) = linux::read_selection() {
+            let _ = app.emit(EVENT_SELECTION_CAPTURED, text);
+        }
+        return;
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        #[cfg(target_os = "macos")]
+        macos::trigger_copy();
+        #[cfg(target_os = "windows")]
+        windows::trigger_copy();
+
+        // Give the OS a moment to populate the clipboard before reading it back.
+        std::thread::sleep(Duration::from_millis(200));
+
+        if let Ok(text) = app.clipboard().read_text() {
+            let _ = app.emit(EVENT_SELECTION_CAPTURED, text);
+        }
+    }
+}
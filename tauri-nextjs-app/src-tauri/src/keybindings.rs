@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri::Manager;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+pub const ACTION_TOGGLE_VISIBILITY: &str = "toggle-visibility";
+pub const ACTION_PLAY_PAUSE: &str = "play-pause";
+pub const ACTION_SPEED_UP: &str = "speed-up";
+pub const ACTION_SPEED_DOWN: &str = "speed-down";
+pub const ACTION_JUMP_BACK: &str = "jump-back-words";
+
+pub struct ActionDescriptor {
+    pub name: &'static str,
+    pub default_accelerator: &'static str,
+}
+
+/// The set of actions a shortcut can be bound to; the Reader menu lists one
+/// item per action.
+pub const ACTIONS: &[ActionDescriptor] = &[
+    ActionDescriptor {
+        name: ACTION_TOGGLE_VISIBILITY,
+        default_accelerator: "Option+C",
+    },
+    ActionDescriptor {
+        name: ACTION_PLAY_PAUSE,
+        default_accelerator: "Option+Space",
+    },
+    ActionDescriptor {
+        name: ACTION_SPEED_UP,
+        default_accelerator: "Option+Up",
+    },
+    ActionDescriptor {
+        name: ACTION_SPEED_DOWN,
+        default_accelerator: "Option+Down",
+    },
+    ActionDescriptor {
+        name: ACTION_JUMP_BACK,
+        default_accelerator: "Option+Left",
+    },
+];
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct KeyBindings(pub HashMap<String, String>);
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self(
+            ACTIONS
+                .iter()
+                .map(|action| (action.name.to_string(), action.default_accelerator.to_string()))
+                .collect(),
+        )
+    }
+}
+
+fn keybindings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    path.push("keybindings.json");
+    Ok(path)
+}
+
+pub fn load(app: &AppHandle) -> KeyBindings {
+    let Ok(path) = keybindings_path(app) else {
+        return KeyBindings::default();
+    };
+
+    if !path.exists() {
+        return KeyBindings::default();
+    }
+
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(app: &AppHandle, bindings: &KeyBindings) -> Result<(), String> {
+    let path = keybindings_path(app)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let json = serde_json::to_string_pretty(bindings).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Registers every accelerator currently in `bindings` with the OS. A single
+/// bad entry (e.g. a duplicate accelerator shared by two actions, or one
+/// already grabbed by another running app) is logged and skipped rather
+/// than aborting the rest of the batch.
+pub fn register_all(app: &AppHandle, bindings: &KeyBindings) {
+    for (action, accelerator) in bindings.0.iter() {
+        if let Err(error) = app.global_shortcut().register(accelerator.as_str()) {
+            eprintln!("failed to register accelerator \"{accelerator}\" for action \"{action}\": {error}");
+        }
+    }
+}
+
+/// Looks up the action bound to a fired shortcut string, if any. Each stored
+/// accelerator is parsed and compared in the shortcut's own string form, so
+/// e.g. "Option+C" matches the fired Alt+C shortcut.
+pub fn action_for_shortcut(bindings: &KeyBindings, shortcut: &str) -> Option<String> {
+    bindings
+        .0
+        .iter()
+        .find(|(_, accelerator)| {
+            accelerator
+                .parse::<Shortcut>()
+                .map_or(false, |parsed| parsed.to_string() == shortcut)
+        })
+        .map(|(action, _)| action.clone())
+}
+
+#[tauri::command]
+pub async fn load_keybindings(app: AppHandle) -> Result<KeyBindings, String> {
+    Ok(load(&app))
+}
+
+#[tauri::command]
+pub async fn set_keybinding(
+    app: AppHandle,
+    action: String,
+    accelerator: String,
+) -> Result<KeyBindings, String> {
+    let mut bindings = load(&app);
+
+    if let Some(conflicting_action) = bindings
+        .0
+        .iter()
+        .find(|(other_action, other_accelerator)| {
+            other_accelerator.as_str() == accelerator && **other_action != action
+        })
+        .map(|(other_action, _)| other_action.clone())
+    {
+        return Err(format!(
+            "\"{accelerator}\" is already bound to \"{conflicting_action}\""
+        ));
+    }
+
+    if let Some(old_accelerator) = bindings.0.get(&action) {
+        let _ = app.global_shortcut().unregister(old_accelerator.as_str());
+    }
+
+    app.global_shortcut()
+        .register(accelerator.as_str())
+        .map_err(|e| e.to_string())?;
+
+    bindings.0.insert(action, accelerator);
+    save(&app, &bindings)?;
+
+    crate::menu::rebuild(&app).await;
+
+    Ok(bindings)
+}
+
+#[tauri::command]
+pub async fn reset_keybindings(app: AppHandle) -> Result<KeyBindings, String> {
+    let current = load(&app);
+    for accelerator in current.0.values() {
+        let _ = app.global_shortcut().unregister(accelerator.as_str());
+    }
+
+    let defaults = KeyBindings::default();
+    register_all(&app, &defaults);
+    save(&app, &defaults)?;
+
+    crate::menu::rebuild(&app).await;
+
+    Ok(defaults)
+}
@@ -4,17 +4,21 @@ use tauri::Emitter;
 use std::sync::Mutex;
 use std::net::TcpStream;
 use std::time::Duration;
-use std::{fs, io::{BufRead, BufReader}, path::PathBuf};
+use std::{fs, path::PathBuf};
 use serde::{Serialize, Deserialize};
 
-#[cfg(target_os = "macos")]
-use core_graphics::event::{CGEvent, CGEventFlags};
-#[cfg(target_os = "macos")]
-use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+mod app_settings;
+mod capture_selection;
+mod keybindings;
+mod llm;
+mod menu;
+mod semantic_index;
+mod word_index;
 
 #[derive(Default)]
 struct AppState {
     is_visible: bool,
+    is_playing: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -63,103 +67,63 @@ struct ProjectSession {
     settings: ProjectSettings,
 }
 
-#[cfg(target_os = "macos")]
-fn simulate_cmd_c() {
-    println!("Attempting to simulate Cmd+C using Core Graphics");
-
-    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState).unwrap();
-    let c_keycode = 8u16; // Key code for 'c'
-
-    // Create key down event with Command modifier
-    if let Ok(key_down_event) = CGEvent::new_keyboard_event(source.clone(), c_keycode, true) {
-        key_down_event.set_flags(CGEventFlags::CGEventFlagCommand);
-        key_down_event.post(core_graphics::event::CGEventTapLocation::HID);
-    }
-
-    // Small delay
-    std::thread::sleep(std::time::Duration::from_millis(10));
-
-    // Create key up event with Command modifier
-    if let Ok(key_up_event) = CGEvent::new_keyboard_event(source, c_keycode, false) {
-        key_up_event.set_flags(CGEventFlags::CGEventFlagCommand);
-        key_up_event.post(core_graphics::event::CGEventTapLocation::HID);
-    }
-
-    println!("Cmd+C simulation completed");
-}
-
-#[tauri::command]
-async fn import_file(app: tauri::AppHandle, original_path: String) -> Result<FileMetadata, String> {
+/// Writes `contents` into the app's `files` directory under a unique name
+/// and builds its [`FileMetadata`]. Shared by `import_file` and anything
+/// else that produces an importable document in-memory (e.g. the `llm`
+/// summarization pipeline).
+fn store_document(app: &tauri::AppHandle, filename: &str, contents: &str) -> Result<FileMetadata, String> {
     let app_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
     let mut target_dir = PathBuf::from(app_dir);
     target_dir.push("files");
 
     fs::create_dir_all(&target_dir).map_err(|e| e.to_string())?;
 
-    let filename = PathBuf::from(&original_path)
-        .file_name()
-        .ok_or("Invalid file name")?
-        .to_string_lossy()
-        .to_string();
-
     // Generate unique filename to avoid conflicts
     let project_id = uuid::Uuid::new_v4().to_string();
     let safe_filename = format!("{}_{}", project_id, filename);
 
     let mut saved_path = target_dir.clone();
     saved_path.push(&safe_filename);
-    fs::copy(&original_path, &saved_path).map_err(|e| e.to_string())?;
+    fs::write(&saved_path, contents).map_err(|e| e.to_string())?;
 
-    // Count total words efficiently
-    let file = fs::File::open(&saved_path).map_err(|e| e.to_string())?;
-    let reader = BufReader::new(file);
-    let mut word_count = 0;
+    let word_count = contents.split_whitespace().count();
+    let saved_path = saved_path.to_string_lossy().to_string();
+    word_index::build_and_write(&saved_path, contents, &ProjectSettings::default())?;
 
-    for line in reader.lines() {
-        if let Ok(line) = line {
-            word_count += line.split_whitespace().count();
-        }
-    }
-
-    let meta = FileMetadata {
+    Ok(FileMetadata {
         id: project_id,
-        filename,
-        saved_path: saved_path.to_string_lossy().to_string(),
+        filename: filename.to_string(),
+        saved_path,
         total_words: word_count,
         current_word_index: 0,
         created_at: chrono::Utc::now().to_rfc3339(),
-    };
+    })
+}
+
+#[tauri::command]
+async fn import_file(app: tauri::AppHandle, original_path: String) -> Result<FileMetadata, String> {
+    let filename = PathBuf::from(&original_path)
+        .file_name()
+        .ok_or("Invalid file name")?
+        .to_string_lossy()
+        .to_string();
+
+    let contents = fs::read_to_string(&original_path).map_err(|e| e.to_string())?;
+    let meta = store_document(&app, &filename, &contents)?;
+
+    // Semantic search is a nice-to-have over the library; don't fail the import over it.
+    let _ = semantic_index::index_document(&app, &meta.id, &contents).await;
 
     Ok(meta)
 }
 
 #[tauri::command]
-async fn load_word_buffer(path: String, start_index: usize, buffer_size: usize) -> Result<Vec<String>, String> {
-    let file = fs::File::open(&path).map_err(|e| e.to_string())?;
-    let reader = BufReader::new(file);
-
-    let mut words = Vec::new();
-    let mut current_index = 0;
-
-    for line in reader.lines() {
-        if let Ok(line) = line {
-            for word in line.split_whitespace() {
-                if current_index >= start_index && words.len() < buffer_size {
-                    words.push(word.to_string());
-                }
-                current_index += 1;
-
-                if words.len() >= buffer_size {
-                    break;
-                }
-            }
-            if words.len() >= buffer_size {
-                break;
-            }
-        }
-    }
-
-    Ok(words)
+async fn load_word_buffer(
+    path: String,
+    start_index: usize,
+    buffer_size: usize,
+) -> Result<Vec<word_index::WordToken>, String> {
+    word_index::load_slice(&word_index::index_path_for(&path), start_index, buffer_size)
 }
 
 #[tauri::command]
@@ -187,6 +151,8 @@ async fn save_project_metadata(app: tauri::AppHandle, metadata: FileMetadata) ->
     let json = serde_json::to_string_pretty(&projects).map_err(|e| e.to_string())?;
     fs::write(projects_file, json).map_err(|e| e.to_string())?;
 
+    menu::rebuild(&app).await;
+
     Ok(())
 }
 
@@ -240,9 +206,31 @@ async fn save_session_progress(app: tauri::AppHandle, project_id: String, word_i
     let json = serde_json::to_string_pretty(&sessions).map_err(|e| e.to_string())?;
     fs::write(sessions_file, json).map_err(|e| e.to_string())?;
 
+    menu::rebuild(&app).await;
+
     Ok(())
 }
 
+/// Whether any session has ever been saved, used to enable/disable the
+/// "Resume Last Session" menu item.
+pub(crate) fn has_sessions(app: &tauri::AppHandle) -> bool {
+    let Ok(app_dir) = app.path().app_data_dir() else {
+        return false;
+    };
+    let mut sessions_file = PathBuf::from(app_dir);
+    sessions_file.push("sessions.json");
+
+    if !sessions_file.exists() {
+        return false;
+    }
+
+    fs::read_to_string(&sessions_file)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Vec<ProjectSession>>(&content).ok())
+        .map(|sessions| !sessions.is_empty())
+        .unwrap_or(false)
+}
+
 #[tauri::command]
 async fn save_project_settings(app: tauri::AppHandle, project_id: String, settings: ProjectSettings) -> Result<(), String> {
     let app_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
@@ -330,6 +318,7 @@ async fn toggle_window(app: tauri::AppHandle, state: tauri::State<'_, Mutex<AppS
         window.show().unwrap();
         window.set_focus().unwrap();
         window.set_always_on_top(true).unwrap();
+        app_settings::apply_to_window(&app, &app_settings::load(&app))?;
         app_state.is_visible = true;
         Ok(true)
     }
@@ -342,6 +331,7 @@ async fn show_window(app: tauri::AppHandle, state: tauri::State<'_, Mutex<AppSta
     window.show().unwrap();
     window.set_focus().unwrap();
     window.set_always_on_top(true).unwrap();
+    app_settings::apply_to_window(&app, &app_settings::load(&app))?;
     let mut app_state = state.lock().unwrap();
     app_state.is_visible = true;
     Ok(())
@@ -357,6 +347,15 @@ async fn hide_window(app: tauri::AppHandle, state: tauri::State<'_, Mutex<AppSta
     Ok(())
 }
 
+/// Lets the frontend report RSVP playback state so the menu's "Pause" item
+/// can reflect whether there is anything to pause.
+#[tauri::command]
+async fn set_playback_state(app: tauri::AppHandle, state: tauri::State<'_, Mutex<AppState>>, is_playing: bool) -> Result<(), String> {
+    state.lock().unwrap().is_playing = is_playing;
+    menu::rebuild(&app).await;
+    Ok(())
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -366,33 +365,39 @@ pub fn run() {
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(
             tauri_plugin_global_shortcut::Builder::new()
-                .with_handler(move |app, _shortcut, event| {
+                .with_handler(move |app, shortcut, event| {
                     if event.state() == tauri_plugin_global_shortcut::ShortcutState::Pressed {
                         let app_handle = app.app_handle().clone();
-                        let state = app_handle.state::<Mutex<AppState>>();
-                        let window = app_handle.get_webview_window("main").unwrap();
-                        let mut app_state = state.lock().unwrap();
-
-                        if app_state.is_visible {
-                            window.set_always_on_top(false).unwrap();
-                            app_handle.hide().unwrap();
-                            app_state.is_visible = false;
-                            app_handle.emit("window-toggled", serde_json::json!({ "isVisible": false })).unwrap();
+                        let bindings = keybindings::load(&app_handle);
+                        let Some(action) = keybindings::action_for_shortcut(&bindings, &shortcut.to_string()) else {
+                            return;
+                        };
+
+                        if action == keybindings::ACTION_TOGGLE_VISIBILITY {
+                            let state = app_handle.state::<Mutex<AppState>>();
+                            let window = app_handle.get_webview_window("main").unwrap();
+                            let mut app_state = state.lock().unwrap();
+
+                            if app_state.is_visible {
+                                window.set_always_on_top(false).unwrap();
+                                app_handle.hide().unwrap();
+                                app_state.is_visible = false;
+                                app_handle.emit("window-toggled", serde_json::json!({ "isVisible": false })).unwrap();
+                            } else {
+                                // Capture whatever text is selected so the reader can show it.
+                                capture_selection::capture_and_emit(&app_handle);
+
+                                // Then show the app
+                                app_handle.show().unwrap();
+                                window.show().unwrap();
+                                window.set_focus().unwrap();
+                                window.set_always_on_top(true).unwrap();
+                                app_settings::apply_to_window(&app_handle, &app_settings::load(&app_handle)).unwrap();
+                                app_state.is_visible = true;
+                                app_handle.emit("window-toggled", serde_json::json!({ "isVisible": true })).unwrap();
+                            }
                         } else {
-                            // First, copy any selected text to clipboard
-                            #[cfg(target_os = "macos")]
-                            simulate_cmd_c();
-
-                            // Delay to ensure copy operation completes
-                            std::thread::sleep(std::time::Duration::from_millis(200));
-
-                            // Then show the app
-                            app_handle.show().unwrap();
-                            window.show().unwrap();
-                            window.set_focus().unwrap();
-                            window.set_always_on_top(true).unwrap();
-                            app_state.is_visible = true;
-                            app_handle.emit("window-toggled", serde_json::json!({ "isVisible": true })).unwrap();
+                            app_handle.emit(&format!("action-{action}"), serde_json::json!({})).unwrap();
                         }
                     }
                 })
@@ -410,8 +415,19 @@ pub fn run() {
             load_project_settings,
             toggle_window,
             show_window,
-            hide_window
+            hide_window,
+            keybindings::load_keybindings,
+            keybindings::set_keybinding,
+            keybindings::reset_keybindings,
+            app_settings::load_app_settings,
+            app_settings::set_visible_on_all_workspaces,
+            llm::summarize_stream,
+            semantic_index::search_library,
+            set_playback_state
         ])
+        .on_menu_event(|app, event| {
+            menu::handle_event(app, event.id().as_ref());
+        })
         .setup(|app| {
             #[cfg(target_os = "macos")]
             app.set_activation_policy(tauri::ActivationPolicy::Accessory);
@@ -442,9 +458,13 @@ pub fn run() {
                 window.set_position(monitor.position().clone()).unwrap();
             }
 
-            app.global_shortcut().register("Option+C").unwrap();
+            let bindings = keybindings::load(&app_handle);
+            keybindings::register_all(&app_handle, &bindings);
 
             window.set_always_on_top(true).unwrap();
+            app_settings::apply_to_window(&app_handle, &app_settings::load(&app_handle)).unwrap();
+
+            tauri::async_runtime::block_on(menu::rebuild(&app_handle));
 
             Ok(())
         })
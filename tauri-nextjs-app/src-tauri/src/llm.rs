@@ -0,0 +1,159 @@
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+use crate::FileMetadata;
+
+pub const EVENT_SUMMARY_WORD: &str = "summary-word";
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryMode {
+    Summary,
+    Simplify,
+    KeyPoints,
+}
+
+impl SummaryMode {
+    fn instruction(&self) -> &'static str {
+        match self {
+            SummaryMode::Summary => "Summarize the following text.",
+            SummaryMode::Simplify => {
+                "Rewrite the following text in simpler language, preserving its meaning."
+            }
+            SummaryMode::KeyPoints => {
+                "Extract the key points from the following text as short sentences."
+            }
+        }
+    }
+
+    fn file_suffix(&self) -> &'static str {
+        match self {
+            SummaryMode::Summary => "summary",
+            SummaryMode::Simplify => "simplified",
+            SummaryMode::KeyPoints => "key-points",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct StreamEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    delta: Option<StreamDelta>,
+}
+
+#[derive(Deserialize)]
+struct StreamDelta {
+    text: Option<String>,
+}
+
+async fn anthropic_api_key(app: &AppHandle) -> Result<String, String> {
+    let store = app.store("settings.json").map_err(|e| e.to_string())?;
+    store
+        .get("anthropic_api_key")
+        .and_then(|value| value.as_str().map(str::to_string))
+        .ok_or_else(|| "No Anthropic API key configured".to_string())
+}
+
+/// Emits every whole word currently buffered in `pending`, leaving behind
+/// any trailing partial word until more text arrives.
+fn emit_whole_words(app: &AppHandle, pending: &mut String) -> Result<(), String> {
+    while let Some(boundary) = pending.find(char::is_whitespace) {
+        let word: String = pending.drain(..=boundary).collect();
+        let word = word.trim();
+        if !word.is_empty() {
+            app.emit(EVENT_SUMMARY_WORD, word).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Streams a summary/simplification/key-points pass over a project's text
+/// through an Anthropic-style messages endpoint, emitting whole words to the
+/// frontend as they arrive, and persists the result as a new project.
+#[tauri::command]
+pub async fn summarize_stream(
+    app: AppHandle,
+    project_id: String,
+    mode: SummaryMode,
+) -> Result<FileMetadata, String> {
+    let projects = crate::load_projects(app.clone()).await?;
+    let project = projects
+        .into_iter()
+        .find(|p| p.id == project_id)
+        .ok_or("Unknown project_id")?;
+
+    let source_text = std::fs::read_to_string(&project.saved_path).map_err(|e| e.to_string())?;
+    let api_key = anthropic_api_key(&app).await?;
+
+    let response = reqwest::Client::new()
+        .post("https://api.anthropic.com/v1/messages")
+        .header("x-api-key", api_key)
+        .header("anthropic-version", "2023-06-01")
+        .json(&serde_json::json!({
+            "model": "claude-3-5-sonnet-latest",
+            "max_tokens": 4096,
+            "stream": true,
+            "messages": [{
+                "role": "user",
+                "content": format!("{}\n\n{}", mode.instruction(), source_text),
+            }],
+        }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let response = response.error_for_status().map_err(|e| e.to_string())?;
+
+    let mut byte_stream = response.bytes_stream();
+    let mut line_buffer: Vec<u8> = Vec::new();
+    let mut pending_word = String::new();
+    let mut summary = String::new();
+
+    while let Some(chunk) = byte_stream.next().await {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        line_buffer.extend_from_slice(&chunk);
+
+        // Only decode once a full line is buffered, so a multi-byte UTF-8
+        // character split across two network chunks doesn't get decoded as
+        // two halves (and turned into replacement characters).
+        while let Some(newline) = line_buffer.iter().position(|byte| *byte == b'\n') {
+            let line_bytes: Vec<u8> = line_buffer.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line_bytes).trim_end().to_string();
+
+            let Some(data) = line.strip_prefix("data: ") else {
+                continue;
+            };
+            let Ok(event) = serde_json::from_str::<StreamEvent>(data) else {
+                continue;
+            };
+
+            match event.event_type.as_str() {
+                "content_block_delta" => {
+                    if let Some(text) = event.delta.and_then(|delta| delta.text) {
+                        summary.push_str(&text);
+                        pending_word.push_str(&text);
+                        emit_whole_words(&app, &mut pending_word)?;
+                    }
+                }
+                "content_block_stop" => {
+                    let last_word = pending_word.trim();
+                    if !last_word.is_empty() {
+                        app.emit(EVENT_SUMMARY_WORD, last_word).map_err(|e| e.to_string())?;
+                    }
+                    pending_word.clear();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let filename = format!("{}-{}.txt", project.filename, mode.file_suffix());
+    let meta = crate::store_document(&app, &filename, &summary)?;
+
+    let _ = crate::semantic_index::index_document(&app, &meta.id, &summary).await;
+
+    Ok(meta)
+}
@@ -0,0 +1,151 @@
+use std::sync::Mutex;
+
+use tauri::menu::{Menu, MenuBuilder, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder};
+use tauri::{AppHandle, Emitter, Manager, Wry};
+
+use crate::keybindings::{self, ACTIONS};
+use crate::AppState;
+
+const MENU_EVENT_IMPORT_FILE: &str = "menu-import-file";
+const MENU_EVENT_RESUME_LAST_SESSION: &str = "menu-resume-last-session";
+const MENU_EVENT_OPEN_PROJECT_PREFIX: &str = "menu-open-project:";
+const MAX_RECENT_PROJECTS: usize = 10;
+
+fn action_label(action: &str, is_playing: bool) -> &'static str {
+    match action {
+        keybindings::ACTION_TOGGLE_VISIBILITY => "Show/Hide Reader",
+        keybindings::ACTION_PLAY_PAUSE => {
+            if is_playing {
+                "Pause"
+            } else {
+                "Play"
+            }
+        }
+        keybindings::ACTION_SPEED_UP => "Speed Up",
+        keybindings::ACTION_SPEED_DOWN => "Speed Down",
+        keybindings::ACTION_JUMP_BACK => "Jump Back",
+        _ => action,
+    }
+}
+
+/// Rebuilds the native menu from the current keybinding, playback, project,
+/// and session state and installs it on the app. Call this any time one of
+/// those changes so the menu stays in sync (e.g. after a keybinding is
+/// rebound, a session is saved, or playback starts/stops).
+pub async fn rebuild(app: &AppHandle) {
+    match build(app).await {
+        Ok(menu) => {
+            let _ = app.set_menu(menu);
+        }
+        Err(error) => eprintln!("failed to rebuild menu: {error}"),
+    }
+}
+
+async fn build(app: &AppHandle) -> Result<Menu<Wry>, String> {
+    let bindings = keybindings::load(app);
+    let is_playing = app.state::<Mutex<AppState>>().lock().unwrap().is_playing;
+    let has_sessions = crate::has_sessions(app);
+    let projects = crate::load_projects(app.clone()).await.unwrap_or_default();
+
+    let mut reader_menu = SubmenuBuilder::new(app, "Reader");
+    for action in ACTIONS {
+        let accelerator = bindings
+            .0
+            .get(action.name)
+            .cloned()
+            .unwrap_or_else(|| action.default_accelerator.to_string());
+
+        // The accelerator is already registered as a system-wide global
+        // shortcut (see `keybindings::register_all`), which fires
+        // regardless of focus. Showing it as the menu item's own key
+        // equivalent as well would make a single keypress dispatch the
+        // action twice while the app is focused, so the item just displays
+        // the binding in its title instead of also acting as its trigger.
+        let enabled = action.name != keybindings::ACTION_PLAY_PAUSE || is_playing;
+        let item = MenuItemBuilder::new(format!("{} ({accelerator})", action_label(action.name, is_playing)))
+            .id(format!("action:{}", action.name))
+            .enabled(enabled)
+            .build(app)
+            .map_err(|e| e.to_string())?;
+        reader_menu = reader_menu.item(&item);
+    }
+    let reader_menu = reader_menu.build().map_err(|e| e.to_string())?;
+
+    let import_item = MenuItemBuilder::new("Import File...")
+        .id(MENU_EVENT_IMPORT_FILE)
+        .accelerator("CmdOrCtrl+O")
+        .build(app)
+        .map_err(|e| e.to_string())?;
+
+    let resume_item = MenuItemBuilder::new("Resume Last Session")
+        .id(MENU_EVENT_RESUME_LAST_SESSION)
+        .enabled(has_sessions)
+        .build(app)
+        .map_err(|e| e.to_string())?;
+
+    let mut open_recent = SubmenuBuilder::new(app, "Open Recent");
+    if projects.is_empty() {
+        let empty_item = MenuItemBuilder::new("No Recent Projects")
+            .enabled(false)
+            .build(app)
+            .map_err(|e| e.to_string())?;
+        open_recent = open_recent.item(&empty_item);
+    } else {
+        for project in projects.iter().take(MAX_RECENT_PROJECTS) {
+            let item = MenuItemBuilder::new(&project.filename)
+                .id(format!("{MENU_EVENT_OPEN_PROJECT_PREFIX}{}", project.id))
+                .build(app)
+                .map_err(|e| e.to_string())?;
+            open_recent = open_recent.item(&item);
+        }
+    }
+    let open_recent_menu = open_recent.build().map_err(|e| e.to_string())?;
+
+    let file_menu = SubmenuBuilder::new(app, "File")
+        .item(&import_item)
+        .item(&resume_item)
+        .separator()
+        .item(&open_recent_menu)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let window_menu = SubmenuBuilder::new(app, "Window")
+        .item(&PredefinedMenuItem::minimize(app, None).map_err(|e| e.to_string())?)
+        .item(&PredefinedMenuItem::close_window(app, None).map_err(|e| e.to_string())?)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    MenuBuilder::new(app)
+        .item(&file_menu)
+        .item(&reader_menu)
+        .item(&window_menu)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Dispatches a clicked menu item's id as an event. Reader items emit
+/// `action-<name>`, as the global shortcut handler does for every action
+/// except toggle-visibility: the handler shows/hides the window itself and
+/// emits `window-toggled`, while this emits `action-toggle-visibility` and
+/// does not show or hide the window.
+pub fn handle_event(app: &AppHandle, event_id: &str) {
+    if let Some(action) = event_id.strip_prefix("action:") {
+        let _ = app.emit(&format!("action-{action}"), serde_json::json!({}));
+        return;
+    }
+
+    if let Some(project_id) = event_id.strip_prefix(MENU_EVENT_OPEN_PROJECT_PREFIX) {
+        let _ = app.emit("menu-open-project", project_id);
+        return;
+    }
+
+    match event_id {
+        MENU_EVENT_IMPORT_FILE => {
+            let _ = app.emit(MENU_EVENT_IMPORT_FILE, ());
+        }
+        MENU_EVENT_RESUME_LAST_SESSION => {
+            let _ = app.emit(MENU_EVENT_RESUME_LAST_SESSION, ());
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,242 @@
+use ndarray::Array1;
+use rusqlite::Connection;
+use serde::Serialize;
+use tauri::AppHandle;
+use tauri::Manager;
+use tauri_plugin_store::StoreExt;
+
+/// Window size and overlap (in words) used when splitting a document for
+/// embedding. Overlap keeps a passage from being cut mid-thought at a
+/// window boundary.
+const WINDOW_SIZE: usize = 300;
+const WINDOW_OVERLAP: usize = 50;
+const TOP_K: usize = 10;
+const SNIPPET_WORD_COUNT: usize = 40;
+
+#[derive(Serialize, Clone)]
+pub struct SearchHit {
+    pub project_id: String,
+    pub start_word_index: usize,
+    pub snippet: String,
+    pub score: f32,
+}
+
+fn db_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let mut path = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    path.push("semantic_index.sqlite3");
+    Ok(path)
+}
+
+fn open_db(app: &AppHandle) -> Result<Connection, String> {
+    let connection = Connection::open(db_path(app)?).map_err(|e| e.to_string())?;
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS embedding_windows (
+                project_id TEXT NOT NULL,
+                start_word_index INTEGER NOT NULL,
+                snippet TEXT NOT NULL,
+                vector BLOB NOT NULL
+            )",
+            (),
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(connection)
+}
+
+fn vector_to_blob(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+fn blob_to_vector(blob: &[u8]) -> Vec<f32> {
+    blob.chunks_exact(4)
+        .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+        .collect()
+}
+
+fn normalize(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = (vector.iter().map(|value| value * value).sum::<f32>()).sqrt();
+    if norm > 0.0 {
+        for value in vector.iter_mut() {
+            *value /= norm;
+        }
+    }
+    vector
+}
+
+/// Cosine similarity between two already-normalized vectors (a dot
+/// product), or `None` if their dimensionality doesn't match (e.g. a window
+/// indexed against a different embedding endpoint/model than the current
+/// query). `Array1::dot` panics on a length mismatch, so callers must check
+/// this instead of scoring directly.
+fn score_if_compatible(query_vector: &Array1<f32>, vector: Vec<f32>) -> Option<f32> {
+    if vector.len() != query_vector.len() {
+        return None;
+    }
+    Some(query_vector.dot(&Array1::from(vector)))
+}
+
+async fn embed(app: &AppHandle, text: &str) -> Result<Vec<f32>, String> {
+    let store = app.store("settings.json").map_err(|e| e.to_string())?;
+    let endpoint = store
+        .get("embedding_endpoint")
+        .and_then(|value| value.as_str().map(str::to_string))
+        .ok_or_else(|| "No embedding endpoint configured".to_string())?;
+    let api_key = store
+        .get("embedding_api_key")
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default();
+
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(endpoint)
+        .bearer_auth(api_key)
+        .json(&serde_json::json!({ "input": text }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let vector = response["data"][0]["embedding"]
+        .as_array()
+        .ok_or("Embedding response missing `data[0].embedding`")?
+        .iter()
+        .filter_map(|value| value.as_f64().map(|value| value as f32))
+        .collect();
+
+    Ok(normalize(vector))
+}
+
+fn snippet_for(words: &[&str]) -> String {
+    words
+        .iter()
+        .take(SNIPPET_WORD_COUNT)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits `text` into overlapping windows, embeds each one, and persists
+/// the (normalized) vectors alongside `{project_id, start_word_index}` so
+/// `search_library` can later resolve a semantic match back to a reading
+/// position.
+pub async fn index_document(app: &AppHandle, project_id: &str, text: &str) -> Result<(), String> {
+    // Same tokenization as the word index, so `start_word_index` lines up
+    // with `load_word_buffer` positions.
+    let words: Vec<&str> = crate::word_index::tokenize(text);
+    if words.is_empty() {
+        return Ok(());
+    }
+
+    let stride = WINDOW_SIZE - WINDOW_OVERLAP;
+    let mut start = 0usize;
+    let mut windows = Vec::new();
+
+    while start < words.len() {
+        let end = (start + WINDOW_SIZE).min(words.len());
+        windows.push((start, words[start..end].join(" "), snippet_for(&words[start..end])));
+        if end == words.len() {
+            break;
+        }
+        start += stride;
+    }
+
+    let connection = open_db(app)?;
+    for (start_word_index, window_text, snippet) in windows {
+        let vector = embed(app, &window_text).await?;
+        connection
+            .execute(
+                "INSERT INTO embedding_windows (project_id, start_word_index, snippet, vector) VALUES (?1, ?2, ?3, ?4)",
+                (project_id, start_word_index as i64, &snippet, vector_to_blob(&vector)),
+            )
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Embeds `query` and ranks every stored window by cosine similarity
+/// (a plain dot product, since vectors are normalized at write time),
+/// returning the top matches.
+#[tauri::command]
+pub async fn search_library(app: AppHandle, query: String) -> Result<Vec<SearchHit>, String> {
+    let query_vector = Array1::from(embed(&app, &query).await?);
+
+    let connection = open_db(&app)?;
+    let mut statement = connection
+        .prepare("SELECT project_id, start_word_index, snippet, vector FROM embedding_windows")
+        .map_err(|e| e.to_string())?;
+
+    let rows = statement
+        .query_map((), |row| {
+            let project_id: String = row.get(0)?;
+            let start_word_index: i64 = row.get(1)?;
+            let snippet: String = row.get(2)?;
+            let vector: Vec<u8> = row.get(3)?;
+            Ok((project_id, start_word_index as usize, snippet, blob_to_vector(&vector)))
+        })
+        .map_err(|e| e.to_string())?;
+
+    let mut hits: Vec<SearchHit> = Vec::new();
+    for row in rows {
+        let (project_id, start_word_index, snippet, vector) = row.map_err(|e| e.to_string())?;
+        let vector_len = vector.len();
+
+        let Some(score) = score_if_compatible(&query_vector, vector) else {
+            eprintln!(
+                "skipping embedding for project {project_id} at word {start_word_index}: \
+                 dimension {vector_len} does not match query dimension {}",
+                query_vector.len()
+            );
+            continue;
+        };
+
+        hits.push(SearchHit {
+            project_id,
+            start_word_index,
+            snippet,
+            score,
+        });
+    }
+
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+    hits.truncate(TOP_K);
+
+    Ok(hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_scales_to_unit_length() {
+        let normalized = normalize(vec![3.0, 4.0]);
+        let norm = (normalized.iter().map(|v| v * v).sum::<f32>()).sqrt();
+        assert!((norm - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn normalize_leaves_zero_vector_untouched() {
+        assert_eq!(normalize(vec![0.0, 0.0, 0.0]), vec![0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn vector_blob_round_trips() {
+        let vector = vec![1.0, -2.5, 0.0, 3.25];
+        assert_eq!(blob_to_vector(&vector_to_blob(&vector)), vector);
+    }
+
+    #[test]
+    fn score_if_compatible_scores_matching_lengths() {
+        let query = Array1::from(vec![1.0, 0.0]);
+        assert_eq!(score_if_compatible(&query, vec![1.0, 0.0]), Some(1.0));
+    }
+
+    #[test]
+    fn score_if_compatible_skips_mismatched_lengths() {
+        let query = Array1::from(vec![1.0, 0.0]);
+        assert_eq!(score_if_compatible(&query, vec![1.0, 0.0, 0.0]), None);
+    }
+}
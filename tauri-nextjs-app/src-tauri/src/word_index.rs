@@ -0,0 +1,220 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ProjectSettings;
+
+/// A single word ready for display: its text, Optimal Recognition Point
+/// (the character the reader should fixate on), and how long to hold it on
+/// screen.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WordToken {
+    pub text: String,
+    pub orp_index: u8,
+    pub delay_ms: f64,
+}
+
+/// Where the columnar index for a saved document lives, alongside its text.
+pub fn index_path_for(saved_path: &str) -> PathBuf {
+    PathBuf::from(format!("{saved_path}.idx"))
+}
+
+fn ends_sentence(word: &str) -> bool {
+    word.trim_end_matches(|c: char| matches!(c, '"' | '\'' | ')' | ']'))
+        .ends_with(|c: char| matches!(c, '.' | '!' | '?'))
+}
+
+/// Roughly `floor((len-1)/4)`, clamped so very short words fixate on their
+/// first character and very long words don't fixate too far to the right.
+fn orp_index(len: usize) -> u8 {
+    if len <= 1 {
+        return 0;
+    }
+    (((len - 1) / 4) as u8).min(5)
+}
+
+/// Character length to use for the ORP fixation point, ignoring trailing
+/// punctuation (quotes, sentence terminators, commas, ...) so e.g.
+/// "reader." fixates the same as "reader" rather than being treated as a
+/// seven-character word.
+fn orp_word_len(word: &str) -> usize {
+    let trimmed = word.trim_end_matches(|c: char| c.is_ascii_punctuation());
+    if trimmed.is_empty() {
+        word.chars().count()
+    } else {
+        trimmed.chars().count()
+    }
+}
+
+fn delay_ms(word: &str, settings: &ProjectSettings) -> f64 {
+    let mut delay = settings.time_per_word + settings.time_per_character * word.chars().count() as f64;
+    if ends_sentence(word) {
+        delay += settings.punctuation_delay;
+    }
+    delay
+}
+
+/// Tokenizes `text` the way the reader needs it split: on whitespace, but
+/// also splitting on em/en dashes that were typed without surrounding
+/// whitespace (e.g. "word—another"), since those otherwise glue two
+/// unrelated words into one oversized display token. Hyphenated compounds
+/// ("well-known") and contractions ("don't") are left as single tokens.
+/// Trailing punctuation stays attached to the displayed token (readers
+/// expect to see "reader." not "reader" + "."), but it's excluded from the
+/// ORP length via `orp_word_len` and detected by `ends_sentence` for the
+/// punctuation delay.
+pub(crate) fn tokenize(text: &str) -> Vec<&str> {
+    text.split_whitespace()
+        .flat_map(split_unspaced_dashes)
+        .collect()
+}
+
+/// Splits `word` on `—`/`–`, keeping the dash attached to the token before
+/// it (so "word—another" becomes `["word—", "another"]`).
+fn split_unspaced_dashes(word: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+
+    for (index, ch) in word.char_indices() {
+        if ch == '—' || ch == '–' {
+            let end = index + ch.len_utf8();
+            if end > start {
+                tokens.push(&word[start..end]);
+            }
+            start = end;
+        }
+    }
+
+    if start < word.len() {
+        tokens.push(&word[start..]);
+    }
+
+    tokens
+}
+
+/// Builds the columnar `{offsets, orp bytes, delays}` index for `text` and
+/// writes it next to the saved document so `load_word_buffer` can slice it
+/// in O(buffer_size) instead of re-splitting the whole document every call.
+///
+/// Layout: `u32 word_count` · `(word_count + 1) x u32 offsets` (into the
+/// trailing word blob) · `word_count x u8 orp` · `word_count x f32 delay_ms`
+/// · the word blob itself.
+pub fn build_and_write(saved_path: &str, text: &str, settings: &ProjectSettings) -> Result<(), String> {
+    let words = tokenize(text);
+
+    let mut offsets: Vec<u32> = Vec::with_capacity(words.len() + 1);
+    let mut orp_bytes: Vec<u8> = Vec::with_capacity(words.len());
+    let mut delays: Vec<f32> = Vec::with_capacity(words.len());
+    let mut blob = String::new();
+
+    let mut offset = 0u32;
+    offsets.push(offset);
+    for word in &words {
+        blob.push_str(word);
+        offset += word.len() as u32;
+        offsets.push(offset);
+        orp_bytes.push(orp_index(orp_word_len(word)));
+        delays.push(delay_ms(word, settings) as f32);
+    }
+
+    let mut buffer = Vec::with_capacity(4 + offsets.len() * 4 + orp_bytes.len() + delays.len() * 4 + blob.len());
+    buffer.extend_from_slice(&(words.len() as u32).to_le_bytes());
+    for offset in &offsets {
+        buffer.extend_from_slice(&offset.to_le_bytes());
+    }
+    buffer.extend_from_slice(&orp_bytes);
+    for delay in &delays {
+        buffer.extend_from_slice(&delay.to_le_bytes());
+    }
+    buffer.extend_from_slice(blob.as_bytes());
+
+    fs::write(index_path_for(saved_path), buffer).map_err(|e| e.to_string())
+}
+
+fn read_u32(data: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(data[at..at + 4].try_into().unwrap())
+}
+
+/// Slices `buffer_size` [`WordToken`]s starting at `start_index` directly
+/// out of the on-disk index, without re-tokenizing the document.
+pub fn load_slice(index_path: &Path, start_index: usize, buffer_size: usize) -> Result<Vec<WordToken>, String> {
+    let data = fs::read(index_path).map_err(|e| e.to_string())?;
+
+    let word_count = read_u32(&data, 0) as usize;
+    let offsets_start = 4;
+    let orp_start = offsets_start + (word_count + 1) * 4;
+    let delays_start = orp_start + word_count;
+    let blob_start = delays_start + word_count * 4;
+
+    let end_index = (start_index + buffer_size).min(word_count);
+    let mut tokens = Vec::with_capacity(end_index.saturating_sub(start_index));
+
+    for i in start_index..end_index {
+        let start = read_u32(&data, offsets_start + i * 4) as usize;
+        let end = read_u32(&data, offsets_start + (i + 1) * 4) as usize;
+        let text = std::str::from_utf8(&data[blob_start + start..blob_start + end])
+            .map_err(|e| e.to_string())?
+            .to_string();
+        let orp_index = data[orp_start + i];
+        let delay_ms = f32::from_le_bytes(data[delays_start + i * 4..delays_start + i * 4 + 4].try_into().unwrap());
+
+        tokens.push(WordToken {
+            text,
+            orp_index,
+            delay_ms: delay_ms as f64,
+        });
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_splits_unspaced_dashes_but_keeps_hyphens_and_contractions() {
+        assert_eq!(tokenize("word—another don't well-known"), vec!["word—", "another", "don't", "well-known"]);
+    }
+
+    #[test]
+    fn orp_index_boundaries() {
+        assert_eq!(orp_index(1), 0);
+        assert_eq!(orp_index(4), 0);
+        assert_eq!(orp_index(5), 1);
+        assert_eq!(orp_index(6), 1);
+        assert_eq!(orp_index(100), 5); // clamped
+    }
+
+    #[test]
+    fn orp_word_len_ignores_trailing_punctuation() {
+        assert_eq!(orp_word_len("reader."), orp_word_len("reader"));
+        assert_eq!(orp_word_len("..."), 3); // all-punctuation word falls back to full length
+    }
+
+    #[test]
+    fn delay_ms_adds_punctuation_delay_at_sentence_end() {
+        let settings = ProjectSettings::default();
+        let base = delay_ms("word", &settings);
+        let with_punctuation = delay_ms("word.", &settings);
+        // The trailing "." also counts as one more character.
+        assert_eq!(with_punctuation - base, settings.punctuation_delay + settings.time_per_character);
+    }
+
+    #[test]
+    fn load_slice_round_trips_through_build_and_write() {
+        let path = std::env::temp_dir().join(format!("blip_word_index_test_{}.txt", std::process::id()));
+        let saved_path = path.to_str().unwrap();
+        let settings = ProjectSettings::default();
+
+        build_and_write(saved_path, "The quick brown fox jumps.", &settings).unwrap();
+        let tokens = load_slice(&index_path_for(saved_path), 1, 2).unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].text, "quick");
+        assert_eq!(tokens[1].text, "brown");
+
+        fs::remove_file(index_path_for(saved_path)).ok();
+    }
+}